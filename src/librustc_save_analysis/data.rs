@@ -54,6 +54,71 @@ pub struct CrateData {
     pub span: Span,
 }
 
+/// Data for an attribute attached to an item, e.g., `#[derive(Clone)]` or
+/// `#[cfg(test)]`.
+#[derive(Clone, Debug, RustcEncodable)]
+pub struct AttributeData {
+    pub value: String,
+    pub span: Span,
+}
+
+/// The current version of the serialized analysis format. Bump this whenever
+/// `Data`, or any type reachable from it, changes in a way that is not
+/// backwards compatible, so that out-of-tree consumers can detect the change
+/// instead of silently mis-parsing a renamed or reordered variant.
+pub const FORMAT_VERSION: u32 = 1;
+
+/// A stable header identifying the crate and compiler that produced a
+/// serialized analysis dump.
+#[derive(Clone, Debug, RustcEncodable)]
+pub struct CrateDumpHeader {
+    pub crate_name: String,
+    pub crate_disambiguator: String,
+    pub rustc_version: String,
+}
+
+/// The top-level envelope for a serialized analysis dump. Consumers should
+/// check `format_version` against the version(s) they support before relying
+/// on the shape of `data`, rejecting or migrating dumps that are too old or
+/// too new rather than mis-parsing them.
+#[derive(Debug, RustcEncodable)]
+pub struct Analysis {
+    pub format_version: u32,
+    pub header: CrateDumpHeader,
+    pub data: Vec<Data>,
+}
+
+/// The visibility of an item, as declared in source.
+#[derive(Clone, Debug, RustcEncodable)]
+pub enum Visibility {
+    Public,
+    Crate,
+    /// `pub(in path)`, where `path` resolves to the given module.
+    Restricted(DefId),
+    Private,
+}
+
+/// A single component of a `Signature`'s rendered text, e.g., a type name, a
+/// trait bound, or a parameter name. `start`/`end` are byte offsets into the
+/// owning `Signature`'s `text`, and `id`, if present, is the definition the
+/// component refers to.
+#[derive(Clone, Debug, RustcEncodable)]
+pub struct SigElement {
+    pub id: Option<DefId>,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// A signature (e.g., of a function, method, typedef, or struct), with the
+/// rendered text plus sub-spans for its individual components. This lets a
+/// consumer resolve, say, the `Vec` in `fn foo() -> Vec<T>` back to its
+/// definition without having to re-parse the text.
+#[derive(Clone, Debug, RustcEncodable)]
+pub struct Signature {
+    pub text: String,
+    pub elements: Vec<SigElement>,
+}
+
 /// Data for any entity in the Rust language. The actual data contained varies
 /// with the kind of entity being queried. See the nested structs for details.
 #[derive(Debug, RustcEncodable)]
@@ -87,7 +152,7 @@ pub enum Data {
     /// Data for a struct declaration.
     StructData(StructData),
     /// Data for a struct variant.
-    StructVariantDat(StructVariantData),
+    StructVariantData(StructVariantData),
     /// Data for a trait declaration.
     TraitData(TraitData),
     /// Data for a tuple variant.
@@ -132,6 +197,9 @@ pub struct EnumData {
     pub qualname: String,
     pub span: Span,
     pub scope: NodeId,
+    pub visibility: Visibility,
+    pub docs: String,
+    pub attributes: Vec<AttributeData>,
 }
 
 /// Data for extern crates.
@@ -143,6 +211,8 @@ pub struct ExternCrateData {
     pub location: String,
     pub span: Span,
     pub scope: NodeId,
+    pub docs: String,
+    pub attributes: Vec<AttributeData>,
 }
 
 /// Data about a function call.
@@ -162,6 +232,10 @@ pub struct FunctionData {
     pub declaration: Option<DefId>,
     pub span: Span,
     pub scope: NodeId,
+    pub sig: Signature,
+    pub visibility: Visibility,
+    pub docs: String,
+    pub attributes: Vec<AttributeData>,
 }
 
 /// Data about a function call.
@@ -208,6 +282,8 @@ pub struct MacroData {
     pub span: Span,
     pub name: String,
     pub qualname: String,
+    pub docs: String,
+    pub attributes: Vec<AttributeData>,
 }
 
 /// Data about a macro use.
@@ -239,6 +315,10 @@ pub struct MethodData {
     pub qualname: String,
     pub span: Span,
     pub scope: NodeId,
+    pub sig: Signature,
+    pub visibility: Visibility,
+    pub docs: String,
+    pub attributes: Vec<AttributeData>,
 }
 
 /// Data for modules.
@@ -250,6 +330,9 @@ pub struct ModData {
     pub span: Span,
     pub scope: NodeId,
     pub filename: String,
+    pub visibility: Visibility,
+    pub docs: String,
+    pub attributes: Vec<AttributeData>,
 }
 
 /// Data for a reference to a module.
@@ -268,7 +351,11 @@ pub struct StructData {
     pub ctor_id: NodeId,
     pub qualname: String,
     pub scope: NodeId,
-    pub value: String
+    pub value: String,
+    pub sig: Signature,
+    pub visibility: Visibility,
+    pub docs: String,
+    pub attributes: Vec<AttributeData>,
 }
 
 #[derive(Debug, RustcEncodable)]
@@ -278,7 +365,10 @@ pub struct StructVariantData {
     pub qualname: String,
     pub type_value: String,
     pub value: String,
-    pub scope: NodeId
+    pub scope: NodeId,
+    pub visibility: Visibility,
+    pub docs: String,
+    pub attributes: Vec<AttributeData>,
 }
 
 #[derive(Debug, RustcEncodable)]
@@ -287,7 +377,10 @@ pub struct TraitData {
     pub id: NodeId,
     pub qualname: String,
     pub scope: NodeId,
-    pub value: String
+    pub value: String,
+    pub visibility: Visibility,
+    pub docs: String,
+    pub attributes: Vec<AttributeData>,
 }
 
 #[derive(Debug, RustcEncodable)]
@@ -298,7 +391,10 @@ pub struct TupleVariantData {
     pub qualname: String,
     pub type_value: String,
     pub value: String,
-    pub scope: NodeId
+    pub scope: NodeId,
+    pub visibility: Visibility,
+    pub docs: String,
+    pub attributes: Vec<AttributeData>,
 }
 
 /// Data for a typedef.
@@ -308,6 +404,9 @@ pub struct TypedefData {
     pub span: Span,
     pub qualname: String,
     pub value: String,
+    pub sig: Signature,
+    pub docs: String,
+    pub attributes: Vec<AttributeData>,
 }
 
 /// Data for a reference to a type or trait.
@@ -346,6 +445,9 @@ pub struct VariableData {
     pub scope: NodeId,
     pub value: String,
     pub type_value: String,
+    pub visibility: Visibility,
+    pub docs: String,
+    pub attributes: Vec<AttributeData>,
 }
 
 /// Data for the use of some item (e.g., the use of a local variable, which